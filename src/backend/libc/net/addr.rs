@@ -0,0 +1,11 @@
+//! Types used for holding raw socket addresses.
+
+use crate::backend::c;
+
+/// A type large enough to hold any kind of socket address for any address
+/// family available on this platform.
+///
+/// This is `libc::sockaddr_storage`, which is guaranteed by the platform to
+/// be large enough and suitably aligned to hold any socket address, no
+/// matter the address family.
+pub type SocketAddrStorage = c::sockaddr_storage;
@@ -0,0 +1,174 @@
+//! Functions for decoding raw C `sockaddr_*` bytes into Rust socket address
+//! types.
+#![allow(unsafe_code)]
+
+use core::mem::size_of;
+
+use crate::backend::c;
+use crate::io;
+use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrLink, SocketAddrV4, SocketAddrV6};
+#[cfg(unix)]
+use crate::net::SocketAddrUnix;
+#[cfg(target_os = "linux")]
+use crate::net::{netlink::SocketAddrNetlink, xdp::SocketAddrXdp};
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+use crate::net::SocketAddrVsock;
+#[cfg(target_os = "linux")]
+use crate::net::SocketAddrAlg;
+
+use super::addr::SocketAddrStorage;
+
+/// Read a socket address encoded in `storage`, which must have been filled
+/// in with `len` valid bytes.
+///
+/// # Safety
+///
+/// `storage` must point to a valid, initialized socket address of `len`
+/// bytes, as filled in by the OS (eg. via `getsockname`, `recvmsg`, or
+/// `accept`).
+pub(crate) unsafe fn read_sockaddr(
+    storage: *const SocketAddrStorage,
+    len: usize,
+) -> io::Result<SocketAddrAny> {
+    if len < size_of::<c::sa_family_t>() {
+        return Err(io::Errno::INVAL);
+    }
+
+    let family = (*storage).ss_family;
+
+    match family as c::c_int {
+        c::AF_INET => {
+            if len < size_of::<c::sockaddr_in>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_in>();
+            Ok(SocketAddrAny::V4(SocketAddrV4::new(
+                Ipv4Addr::from(u32::from_be(decode.sin_addr.s_addr)),
+                u16::from_be(decode.sin_port),
+            )))
+        }
+        c::AF_INET6 => {
+            if len < size_of::<c::sockaddr_in6>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_in6>();
+            Ok(SocketAddrAny::V6(SocketAddrV6::new(
+                Ipv6Addr::from(decode.sin6_addr.s6_addr),
+                u16::from_be(decode.sin6_port),
+                decode.sin6_flowinfo,
+                decode.sin6_scope_id,
+            )))
+        }
+        #[cfg(unix)]
+        c::AF_UNIX => {
+            if len < size_of::<c::sa_family_t>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_un>();
+            // `len` may be shorter than `size_of::<sockaddr_un>()`: Unix
+            // addresses are variable-length (a path, an abstract-namespace
+            // name, or unnamed), so the caller-reported `len` is the source
+            // of truth for how much of `sun_path` is meaningful.
+            Ok(SocketAddrAny::Unix(SocketAddrUnix::new_raw(
+                *decode,
+                len as c::socklen_t,
+            )))
+        }
+        #[cfg(target_os = "linux")]
+        c::AF_XDP => {
+            if len < size_of::<c::sockaddr_xdp>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_xdp>();
+            Ok(SocketAddrAny::Xdp(SocketAddrXdp::new_raw(*decode)))
+        }
+        #[cfg(target_os = "linux")]
+        c::AF_NETLINK => {
+            if len < size_of::<c::sockaddr_nl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_nl>();
+            Ok(SocketAddrAny::Netlink(SocketAddrNetlink::new_raw(*decode)))
+        }
+        #[cfg(target_os = "linux")]
+        c::AF_PACKET => {
+            if len < size_of::<c::sockaddr_ll>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_ll>();
+            let halen = (decode.sll_halen as usize).min(decode.sll_addr.len());
+            Ok(SocketAddrAny::Link(SocketAddrLink::new(
+                decode.sll_ifindex as u32,
+                u16::from_be(decode.sll_protocol),
+                decode.sll_hatype,
+                decode.sll_pkttype,
+                &decode.sll_addr[..halen],
+            )))
+        }
+        #[cfg(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "dragonfly"
+        ))]
+        c::AF_LINK => {
+            if len < size_of::<c::sockaddr_dl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_dl>();
+            let nlen = (decode.sdl_nlen as usize).min(decode.sdl_data.len());
+            let alen = (decode.sdl_alen as usize)
+                .min(decode.sdl_data.len() - nlen)
+                .min(8);
+            let mut addr = [0_u8; 8];
+            for (dst, src) in addr[..alen].iter_mut().zip(&decode.sdl_data[nlen..nlen + alen]) {
+                *dst = *src as u8;
+            }
+            Ok(SocketAddrAny::Link(SocketAddrLink::new(
+                decode.sdl_index as u32,
+                0,
+                decode.sdl_type as u16,
+                0,
+                &addr[..alen],
+            )))
+        }
+        #[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+        c::AF_VSOCK => {
+            if len < size_of::<c::sockaddr_vm>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_vm>();
+            Ok(SocketAddrAny::Vsock(SocketAddrVsock::new(
+                decode.svm_cid,
+                decode.svm_port,
+            )))
+        }
+        #[cfg(target_os = "linux")]
+        c::AF_ALG => {
+            if len < size_of::<c::sockaddr_alg>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_alg>();
+            let alg_type = decode
+                .salg_type
+                .split(|&b| b == 0)
+                .next()
+                .unwrap_or(&decode.salg_type);
+            let alg_name = decode
+                .salg_name
+                .split(|&b| b == 0)
+                .next()
+                .unwrap_or(&decode.salg_name);
+            let mut addr = SocketAddrAlg::new(
+                core::str::from_utf8(alg_type).map_err(|_| io::Errno::INVAL)?,
+                core::str::from_utf8(alg_name).map_err(|_| io::Errno::INVAL)?,
+            )?;
+            addr.set_feat(decode.salg_feat);
+            addr.set_mask(decode.salg_mask);
+            Ok(SocketAddrAny::Alg(addr))
+        }
+        _ => Err(io::Errno::INVAL),
+    }
+}
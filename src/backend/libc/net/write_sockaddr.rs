@@ -0,0 +1,120 @@
+//! Functions for encoding Rust socket address types into their raw C
+//! `sockaddr_*` representations.
+
+use crate::backend::c;
+#[cfg(target_os = "linux")]
+use crate::net::SocketAddrAlg;
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+use crate::net::SocketAddrVsock;
+use crate::net::{SocketAddrLink, SocketAddrV4, SocketAddrV6};
+
+/// Encode a V4 socket address into `c::sockaddr_in`.
+pub(crate) fn encode_sockaddr_v4(addr: &SocketAddrV4) -> c::sockaddr_in {
+    c::sockaddr_in {
+        #[cfg(any(
+            target_os = "aix",
+            bsd,
+            target_os = "haiku",
+            target_os = "hurd",
+            target_os = "nto"
+        ))]
+        sin_len: core::mem::size_of::<c::sockaddr_in>() as u8,
+        sin_family: c::AF_INET as c::sa_family_t,
+        sin_port: u16::to_be(addr.port()),
+        sin_addr: c::in_addr {
+            s_addr: u32::from_ne_bytes(addr.ip().octets()),
+        },
+        sin_zero: [0; 8],
+    }
+}
+
+/// Encode a V6 socket address into `c::sockaddr_in6`.
+pub(crate) fn encode_sockaddr_v6(addr: &SocketAddrV6) -> c::sockaddr_in6 {
+    c::sockaddr_in6 {
+        #[cfg(any(
+            target_os = "aix",
+            bsd,
+            target_os = "haiku",
+            target_os = "hurd",
+            target_os = "nto"
+        ))]
+        sin6_len: core::mem::size_of::<c::sockaddr_in6>() as u8,
+        sin6_family: c::AF_INET6 as c::sa_family_t,
+        sin6_port: u16::to_be(addr.port()),
+        sin6_addr: c::in6_addr {
+            s6_addr: addr.ip().octets(),
+        },
+        sin6_flowinfo: addr.flowinfo(),
+        sin6_scope_id: addr.scope_id(),
+    }
+}
+
+/// Encode a link-layer address into the platform's hardware-address
+/// `sockaddr`.
+///
+/// On Linux this is `c::sockaddr_ll` (`AF_PACKET`); on the BSDs and macOS
+/// this is `c::sockaddr_dl` (`AF_LINK`).
+#[cfg(target_os = "linux")]
+pub(crate) fn encode_sockaddr_link(addr: &SocketAddrLink) -> c::sockaddr_ll {
+    let mut sll_addr = [0_u8; 8];
+    let bytes = addr.address();
+    sll_addr[..bytes.len()].copy_from_slice(bytes);
+
+    c::sockaddr_ll {
+        sll_family: c::AF_PACKET as u16,
+        sll_protocol: u16::to_be(addr.protocol()),
+        sll_ifindex: addr.ifindex() as _,
+        sll_hatype: addr.hardware_type(),
+        sll_pkttype: addr.packet_type(),
+        sll_halen: addr.address().len() as u8,
+        sll_addr,
+    }
+}
+
+/// Encode a link-layer address into `c::sockaddr_dl`.
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+pub(crate) fn encode_sockaddr_link(addr: &SocketAddrLink) -> c::sockaddr_dl {
+    let bytes = addr.address();
+
+    let mut sdl: c::sockaddr_dl = unsafe { core::mem::zeroed() };
+    sdl.sdl_len = core::mem::size_of::<c::sockaddr_dl>() as u8;
+    sdl.sdl_family = c::AF_LINK as u8;
+    sdl.sdl_index = addr.ifindex() as u16;
+    sdl.sdl_alen = bytes.len() as u8;
+    for (dst, src) in sdl.sdl_data.iter_mut().zip(bytes) {
+        *dst = *src as c::c_char;
+    }
+    sdl
+}
+
+/// Encode a VSOCK address into `c::sockaddr_vm`.
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+pub(crate) fn encode_sockaddr_vsock(addr: &SocketAddrVsock) -> c::sockaddr_vm {
+    let mut vm: c::sockaddr_vm = unsafe { core::mem::zeroed() };
+    vm.svm_family = c::AF_VSOCK as _;
+    vm.svm_cid = addr.cid();
+    vm.svm_port = addr.port();
+    vm
+}
+
+/// Encode an `AF_ALG` address into `c::sockaddr_alg`.
+#[cfg(target_os = "linux")]
+pub(crate) fn encode_sockaddr_alg(addr: &SocketAddrAlg) -> c::sockaddr_alg {
+    let alg_type = addr.alg_type().as_bytes();
+    let alg_name = addr.alg_name().as_bytes();
+
+    let mut sa: c::sockaddr_alg = unsafe { core::mem::zeroed() };
+    sa.salg_family = c::AF_ALG as _;
+    sa.salg_type[..alg_type.len()].copy_from_slice(alg_type);
+    sa.salg_feat = addr.feat();
+    sa.salg_mask = addr.mask();
+    sa.salg_name[..alg_name.len()].copy_from_slice(alg_name);
+    sa
+}
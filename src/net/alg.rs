@@ -0,0 +1,163 @@
+//! `AF_ALG` socket addresses, for driving the Linux kernel crypto API.
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::io;
+use crate::net::SocketAddress;
+#[cfg(feature = "std")]
+use core::fmt;
+
+/// `struct sockaddr_alg`
+#[doc(alias = "sockaddr_alg")]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SocketAddrAlg {
+    alg_type: [u8; 14],
+    alg_type_len: usize,
+    feat: u32,
+    mask: u32,
+    alg_name: [u8; 64],
+    alg_name_len: usize,
+}
+
+impl SocketAddrAlg {
+    /// Construct a new `SocketAddrAlg` for the given algorithm type (eg.
+    /// `"hash"`, `"skcipher"`, `"aead"`, `"rng"`) and algorithm name (eg.
+    /// `"sha256"`, `"cbc(aes)"`).
+    ///
+    /// Returns [`io::Errno::NAMETOOLONG`] if `alg_type` does not fit in the
+    /// 14-byte `salg_type` field, or `alg_name` does not fit in the 64-byte
+    /// `salg_name` field (both fields must also hold a terminating NUL).
+    #[inline]
+    pub fn new(alg_type: impl AsRef<str>, alg_name: impl AsRef<str>) -> io::Result<Self> {
+        fn new_impl(alg_type: &str, alg_name: &str) -> io::Result<SocketAddrAlg> {
+            let mut addr = SocketAddrAlg {
+                alg_type: [0; 14],
+                alg_type_len: 0,
+                feat: 0,
+                mask: 0,
+                alg_name: [0; 64],
+                alg_name_len: 0,
+            };
+
+            let type_bytes = alg_type.as_bytes();
+            if type_bytes.len() >= addr.alg_type.len() {
+                return Err(io::Errno::NAMETOOLONG);
+            }
+            addr.alg_type[..type_bytes.len()].copy_from_slice(type_bytes);
+            addr.alg_type_len = type_bytes.len();
+
+            let name_bytes = alg_name.as_bytes();
+            if name_bytes.len() >= addr.alg_name.len() {
+                return Err(io::Errno::NAMETOOLONG);
+            }
+            addr.alg_name[..name_bytes.len()].copy_from_slice(name_bytes);
+            addr.alg_name_len = name_bytes.len();
+
+            Ok(addr)
+        }
+
+        new_impl(alg_type.as_ref(), alg_name.as_ref())
+    }
+
+    /// Set the feature bits (`salg_feat`), eg. `CRYPTO_ALG_INTERNAL`.
+    #[inline]
+    pub fn set_feat(&mut self, feat: u32) {
+        self.feat = feat;
+    }
+
+    /// Set the mask bits (`salg_mask`).
+    #[inline]
+    pub fn set_mask(&mut self, mask: u32) {
+        self.mask = mask;
+    }
+
+    /// Returns the algorithm type, eg. `"hash"` or `"skcipher"`.
+    #[inline]
+    pub fn alg_type(&self) -> &str {
+        core::str::from_utf8(&self.alg_type[..self.alg_type_len]).unwrap_or("")
+    }
+
+    /// Returns the algorithm name, eg. `"sha256"` or `"cbc(aes)"`.
+    #[inline]
+    pub fn alg_name(&self) -> &str {
+        core::str::from_utf8(&self.alg_name[..self.alg_name_len]).unwrap_or("")
+    }
+
+    /// Returns the feature bits (`salg_feat`) set via [`Self::set_feat`].
+    #[inline]
+    pub const fn feat(&self) -> u32 {
+        self.feat
+    }
+
+    /// Returns the mask bits (`salg_mask`) set via [`Self::set_mask`].
+    #[inline]
+    pub const fn mask(&self) -> u32 {
+        self.mask
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for SocketAddrAlg {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SocketAddrAlg")
+            .field("alg_type", &self.alg_type())
+            .field("alg_name", &self.alg_name())
+            .finish()
+    }
+}
+
+#[cfg(target_os = "linux")]
+unsafe impl SocketAddress for SocketAddrAlg {
+    type CSockAddr = c::sockaddr_alg;
+
+    fn encode(&self) -> Self::CSockAddr {
+        crate::backend::net::write_sockaddr::encode_sockaddr_alg(self)
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::SocketAddrAlg;
+    use crate::io;
+    use crate::net::{SocketAddrAny, SocketAddress};
+
+    #[test]
+    fn round_trips_through_encode_and_read() {
+        let mut addr = SocketAddrAlg::new("hash", "sha256").unwrap();
+        addr.set_feat(1);
+        addr.set_mask(2);
+
+        let decoded = addr
+            .with_sockaddr(|ptr, len| unsafe { SocketAddrAny::read(ptr.cast(), len as usize) });
+
+        assert_eq!(decoded.unwrap(), SocketAddrAny::Alg(addr));
+    }
+
+    #[test]
+    fn alg_type_at_capacity_is_rejected() {
+        // `alg_type` is a 14-byte field that must also hold a terminating
+        // NUL, so a 14-byte type string doesn't fit.
+        let alg_type = "a".repeat(14);
+        assert_eq!(
+            SocketAddrAlg::new(&alg_type, "sha256").unwrap_err(),
+            io::Errno::NAMETOOLONG
+        );
+
+        // One byte shorter fits.
+        assert!(SocketAddrAlg::new(&alg_type[..13], "sha256").is_ok());
+    }
+
+    #[test]
+    fn alg_name_at_capacity_is_rejected() {
+        // `alg_name` is a 64-byte field that must also hold a terminating
+        // NUL, so a 64-byte name string doesn't fit.
+        let alg_name = "a".repeat(64);
+        assert_eq!(
+            SocketAddrAlg::new("hash", &alg_name).unwrap_err(),
+            io::Errno::NAMETOOLONG
+        );
+
+        // One byte shorter fits.
+        assert!(SocketAddrAlg::new("hash", &alg_name[..63]).is_ok());
+    }
+}
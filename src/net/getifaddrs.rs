@@ -0,0 +1,173 @@
+//! An iterator over the local network interfaces and their addresses,
+//! built on top of the platform `getifaddrs`/`freeifaddrs`.
+//!
+//! `getifaddrs`/`freeifaddrs` are POSIX APIs with no equivalent in rustix's
+//! Windows backend, so this module is Unix-only.
+#![cfg(unix)]
+#![allow(unsafe_code)]
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ffi::CStr;
+use core::mem::size_of;
+use core::ptr::null_mut;
+
+use crate::backend::c;
+use crate::io;
+use crate::net::SocketAddrAny;
+
+/// One entry of the interface list returned by [`getifaddrs`].
+///
+/// This corresponds to one `struct ifaddrs` node.
+#[derive(Clone, Debug)]
+pub struct InterfaceAddress {
+    name: String,
+    flags: u32,
+    address: Option<SocketAddrAny>,
+    netmask: Option<SocketAddrAny>,
+    broadcast_or_destination: Option<SocketAddrAny>,
+}
+
+impl InterfaceAddress {
+    /// The name of the interface, eg. `"eth0"` or `"lo"`.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The interface's flags (`ifa_flags`), eg. `IFF_UP` or `IFF_LOOPBACK`.
+    #[inline]
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// The interface's address, if any.
+    #[inline]
+    pub fn address(&self) -> Option<&SocketAddrAny> {
+        self.address.as_ref()
+    }
+
+    /// The interface's netmask, if any.
+    #[inline]
+    pub fn netmask(&self) -> Option<&SocketAddrAny> {
+        self.netmask.as_ref()
+    }
+
+    /// The interface's broadcast address or point-to-point destination
+    /// address, if any. Which of the two this is depends on the
+    /// `IFF_BROADCAST`/`IFF_POINTOPOINT` bits in [`Self::flags`].
+    #[inline]
+    pub fn broadcast_or_destination(&self) -> Option<&SocketAddrAny> {
+        self.broadcast_or_destination.as_ref()
+    }
+}
+
+/// Enumerate the local network interfaces, via `getifaddrs`.
+///
+/// This walks the linked list returned by the OS, decodes each address into
+/// an owned [`SocketAddrAny`], and frees the list before returning, so the
+/// result borrows nothing from the OS.
+#[doc(alias = "getifaddrs")]
+pub fn getifaddrs() -> io::Result<alloc::vec::IntoIter<InterfaceAddress>> {
+    unsafe {
+        let mut head: *mut c::ifaddrs = null_mut();
+        if c::getifaddrs(&mut head) != 0 {
+            return Err(io::Errno::last_os_error());
+        }
+
+        let mut result = Vec::new();
+        let mut ifa = head;
+        while !ifa.is_null() {
+            let entry = &*ifa;
+
+            // Per `getifaddrs(3)`, `ifa_addr` may be null (eg. for some
+            // PPP interfaces); skip such entries.
+            if !entry.ifa_addr.is_null() {
+                let name = CStr::from_ptr(entry.ifa_name).to_string_lossy().to_string();
+
+                result.push(InterfaceAddress {
+                    name,
+                    flags: entry.ifa_flags as u32,
+                    address: decode_ifa_sockaddr(entry.ifa_addr),
+                    netmask: decode_ifa_sockaddr(entry.ifa_netmask),
+                    broadcast_or_destination: decode_ifa_sockaddr(entry.ifa_ifu),
+                });
+            }
+
+            ifa = entry.ifa_next;
+        }
+
+        c::freeifaddrs(head);
+
+        Ok(result.into_iter())
+    }
+}
+
+/// Decode a possibly-null `sockaddr` pointer from an `ifaddrs` entry into an
+/// owned `SocketAddrAny`, picking the length to read based on the address
+/// family.
+unsafe fn decode_ifa_sockaddr(ptr: *mut c::sockaddr) -> Option<SocketAddrAny> {
+    if ptr.is_null() {
+        return None;
+    }
+
+    let family = (*ptr.cast::<c::sockaddr_storage>()).ss_family;
+    let len = match family as c::c_int {
+        c::AF_INET => size_of::<c::sockaddr_in>(),
+        c::AF_INET6 => size_of::<c::sockaddr_in6>(),
+        #[cfg(target_os = "linux")]
+        c::AF_PACKET => size_of::<c::sockaddr_ll>(),
+        #[cfg(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "dragonfly"
+        ))]
+        c::AF_LINK => size_of::<c::sockaddr_dl>(),
+        _ => size_of::<c::sockaddr_storage>(),
+    };
+
+    SocketAddrAny::read(ptr.cast(), len).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_ifa_sockaddr;
+    use crate::net::{Ipv4Addr, SocketAddrAny, SocketAddrV4};
+    use crate::net::SocketAddress;
+
+    #[test]
+    fn decode_ifa_sockaddr_round_trips_v4() {
+        let addr = SocketAddrV4::new(Ipv4Addr::new(192, 0, 2, 1), 0);
+
+        let decoded = addr.with_sockaddr(|ptr, _len| unsafe {
+            decode_ifa_sockaddr(ptr as *mut crate::backend::c::sockaddr)
+        });
+
+        assert_eq!(decoded, Some(SocketAddrAny::V4(addr)));
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    #[test]
+    fn decode_ifa_sockaddr_round_trips_link() {
+        use crate::net::SocketAddrLink;
+
+        let addr = SocketAddrLink::new(1, 0, 0, 0, &[0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        let decoded = addr.with_sockaddr(|ptr, _len| unsafe {
+            decode_ifa_sockaddr(ptr as *mut crate::backend::c::sockaddr)
+        });
+
+        assert_eq!(decoded, Some(SocketAddrAny::Link(addr)));
+    }
+}
@@ -0,0 +1,162 @@
+//! Link-layer (hardware) socket addresses.
+//!
+//! On Linux this is `AF_PACKET`/`struct sockaddr_ll`; on the BSDs and macOS
+//! this is `AF_LINK`/`struct sockaddr_dl`.
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::net::SocketAddress;
+#[cfg(feature = "std")]
+use core::fmt;
+
+/// A link-layer address, such as an Ethernet MAC address, identified by
+/// network interface index.
+#[doc(alias = "sockaddr_ll")]
+#[doc(alias = "sockaddr_dl")]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct SocketAddrLink {
+    ifindex: u32,
+    protocol: u16,
+    hatype: u16,
+    pkttype: u8,
+    addr_len: u8,
+    addr: [u8; 8],
+}
+
+impl SocketAddrLink {
+    /// Construct a new `SocketAddrLink`.
+    ///
+    /// `addr` is the hardware address, such as a 6-byte Ethernet MAC
+    /// address. At most 8 bytes are stored; any additional bytes are
+    /// dropped.
+    #[inline]
+    pub fn new(ifindex: u32, protocol: u16, hatype: u16, pkttype: u8, addr: &[u8]) -> Self {
+        let addr_len = addr.len().min(8);
+        let mut bytes = [0_u8; 8];
+        bytes[..addr_len].copy_from_slice(&addr[..addr_len]);
+        Self {
+            ifindex,
+            protocol,
+            hatype,
+            pkttype,
+            addr_len: addr_len as u8,
+            addr: bytes,
+        }
+    }
+
+    /// Returns the index of the network interface this address refers to.
+    #[inline]
+    pub const fn ifindex(&self) -> u32 {
+        self.ifindex
+    }
+
+    /// Returns the protocol associated with this address (`sll_protocol` on
+    /// Linux; unused on the BSDs and macOS).
+    #[inline]
+    pub const fn protocol(&self) -> u16 {
+        self.protocol
+    }
+
+    /// Returns the ARPHRD hardware type (`sll_hatype` on Linux; the
+    /// `IFT_*` type on the BSDs and macOS).
+    #[inline]
+    pub const fn hardware_type(&self) -> u16 {
+        self.hatype
+    }
+
+    /// Returns the packet type (`sll_pkttype`, eg. `PACKET_HOST` or
+    /// `PACKET_BROADCAST`). `sockaddr_dl` has no such field, so on the BSDs
+    /// and macOS this is not encoded, and addresses decoded there have `0`.
+    #[inline]
+    pub const fn packet_type(&self) -> u8 {
+        self.pkttype
+    }
+
+    /// Returns the hardware address bytes, such as a 6-byte Ethernet MAC
+    /// address.
+    #[inline]
+    pub fn address(&self) -> &[u8] {
+        &self.addr[..self.addr_len as usize]
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for SocketAddrLink {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SocketAddrLink")
+            .field("ifindex", &self.ifindex)
+            .field("address", &self.address())
+            .finish()
+    }
+}
+
+#[cfg(target_os = "linux")]
+unsafe impl SocketAddress for SocketAddrLink {
+    type CSockAddr = c::sockaddr_ll;
+
+    fn encode(&self) -> Self::CSockAddr {
+        crate::backend::net::write_sockaddr::encode_sockaddr_link(self)
+    }
+}
+
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+unsafe impl SocketAddress for SocketAddrLink {
+    type CSockAddr = c::sockaddr_dl;
+
+    fn encode(&self) -> Self::CSockAddr {
+        crate::backend::net::write_sockaddr::encode_sockaddr_link(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SocketAddrLink;
+    use crate::net::{SocketAddrAny, SocketAddress};
+
+    // On Linux, `sockaddr_ll` has fields for all of protocol/hatype/pkttype,
+    // so the round trip is exact.
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn round_trips_through_encode_and_read() {
+        let addr = SocketAddrLink::new(7, 0x0800, 1, 3, &[0x02, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        let decoded = addr
+            .with_sockaddr(|ptr, len| unsafe { SocketAddrAny::read(ptr.cast(), len as usize) });
+
+        assert_eq!(decoded.unwrap(), SocketAddrAny::Link(addr));
+    }
+
+    // `sockaddr_dl` only carries the interface index and the address bytes
+    // through `encode_sockaddr_link`/decode; `protocol` and `pkttype` are
+    // Linux-only concepts.
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    #[test]
+    fn round_trips_through_encode_and_read() {
+        let addr = SocketAddrLink::new(7, 0, 0, 0, &[0x02, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        let decoded = addr
+            .with_sockaddr(|ptr, len| unsafe { SocketAddrAny::read(ptr.cast(), len as usize) });
+
+        match decoded.unwrap() {
+            SocketAddrAny::Link(decoded) => {
+                assert_eq!(decoded.ifindex(), addr.ifindex());
+                assert_eq!(decoded.address(), addr.address());
+            }
+            _ => panic!("expected SocketAddrAny::Link"),
+        }
+    }
+}
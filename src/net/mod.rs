@@ -0,0 +1,48 @@
+//! Network-related functionality.
+//!
+//! This module is incomplete in this checkout: it only declares the
+//! submodules touched by the socket-address work below. The rest of
+//! rustix's `net` module (`SocketAddr`, `SocketAddrV4`/`V6`, `SocketAddrUnix`,
+//! `AddressFamily`, the `xdp`/`netlink` modules, etc.) lives outside this
+//! checkout and is not reproduced here.
+
+#[cfg(target_os = "linux")]
+pub mod alg;
+#[cfg(unix)]
+mod getifaddrs;
+#[cfg(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+pub mod link;
+mod socket_addr_any;
+mod socket_address;
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+pub mod vsock;
+
+#[cfg(target_os = "linux")]
+pub use alg::SocketAddrAlg;
+#[cfg(unix)]
+pub use getifaddrs::{getifaddrs, InterfaceAddress};
+#[cfg(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+pub use link::SocketAddrLink;
+pub use socket_addr_any::{SocketAddrAny, SocketAddrStorage};
+pub use socket_address::SocketAddress;
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+pub use vsock::{
+    SocketAddrVsock, VMADDR_CID_ANY, VMADDR_CID_HOST, VMADDR_CID_HYPERVISOR, VMADDR_CID_LOCAL,
+    VMADDR_PORT_ANY,
+};
@@ -14,11 +14,26 @@ use crate::backend::c;
 use crate::net::SocketAddrUnix;
 #[cfg(target_os = "linux")]
 use crate::net::{netlink::SocketAddrNetlink, xdp::SocketAddrXdp};
+#[cfg(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+use crate::net::link::SocketAddrLink;
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+use crate::net::vsock::SocketAddrVsock;
+#[cfg(target_os = "linux")]
+use crate::net::alg::SocketAddrAlg;
 use crate::net::{AddressFamily, SocketAddr, SocketAddrV4, SocketAddrV6};
 use crate::{backend, io};
 #[cfg(feature = "std")]
 use core::fmt;
 use core::mem;
+use core::mem::MaybeUninit;
 use core::ptr::copy_nonoverlapping;
 
 pub use backend::net::addr::SocketAddrStorage;
@@ -43,6 +58,23 @@ pub enum SocketAddrAny {
     /// `struct sockaddr_nl`
     #[cfg(target_os = "linux")]
     Netlink(SocketAddrNetlink),
+    /// `struct sockaddr_ll` (Linux) or `struct sockaddr_dl` (BSD/macOS)
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    Link(SocketAddrLink),
+    /// `struct sockaddr_vm`
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+    Vsock(SocketAddrVsock),
+    /// `struct sockaddr_alg`
+    #[cfg(target_os = "linux")]
+    Alg(SocketAddrAlg),
 }
 
 impl From<SocketAddr> for SocketAddrAny {
@@ -90,6 +122,21 @@ impl SocketAddrAny {
             Self::Xdp(_) => AddressFamily::XDP,
             #[cfg(target_os = "linux")]
             Self::Netlink(_) => AddressFamily::NETLINK,
+            #[cfg(target_os = "linux")]
+            Self::Link(_) => AddressFamily::PACKET,
+            #[cfg(any(
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "dragonfly"
+            ))]
+            Self::Link(_) => AddressFamily::LINK,
+            #[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+            Self::Vsock(_) => AddressFamily::VSOCK,
+            #[cfg(target_os = "linux")]
+            Self::Alg(_) => AddressFamily::ALG,
         }
     }
 
@@ -120,6 +167,53 @@ impl SocketAddrAny {
     pub unsafe fn read(storage: *const SocketAddrStorage, len: usize) -> io::Result<Self> {
         backend::net::read_sockaddr::read_sockaddr(storage, len)
     }
+
+    /// Construct a `SocketAddrAny` from initialized, OS-filled socket
+    /// address storage.
+    ///
+    /// This is a safe alternative to [`Self::read`]: since `storage` is
+    /// already a valid, initialized value, decoding it can't read
+    /// uninitialized or out-of-bounds memory. `len` is validated against
+    /// the `ss_family` recorded in `storage` (eg. an `AF_INET` family with
+    /// a `len` shorter than `sockaddr_in` is rejected) before any
+    /// family-specific fields are read.
+    #[inline]
+    pub fn try_new(storage: &SocketAddrStorage, len: usize) -> io::Result<Self> {
+        // SAFETY: `storage` is a reference, so it is guaranteed to point to
+        // `size_of::<SocketAddrStorage>()` bytes of initialized memory, and
+        // `read_sockaddr` validates `len` against the decoded family before
+        // reading any family-specific fields.
+        unsafe { Self::read(storage, len) }
+    }
+
+    /// Call `f` with an uninitialized socket address buffer and a
+    /// `socklen_t` preset to its size, then decode whatever `f` wrote into
+    /// it.
+    ///
+    /// This is intended for APIs like `getsockname`, `getpeername`, and
+    /// `accept`, which fill in a `sockaddr` buffer and report back how many
+    /// bytes they wrote.
+    ///
+    /// # Safety
+    ///
+    /// `f` must initialize `storage` up to at least the `len` it sets
+    /// before returning `Ok`. This is the same obligation as `SocketAddress`
+    /// implementations have for the buffers they hand to the OS: nothing
+    /// here checks that `f` actually did so, and if it didn't, the
+    /// subsequent decode reads uninitialized memory.
+    pub unsafe fn initialize<F>(f: F) -> io::Result<Self>
+    where
+        F: FnOnce(&mut MaybeUninit<SocketAddrStorage>, &mut backend::c::socklen_t) -> io::Result<()>,
+    {
+        let mut storage = MaybeUninit::<SocketAddrStorage>::uninit();
+        let mut len = mem::size_of::<SocketAddrStorage>() as backend::c::socklen_t;
+
+        f(&mut storage, &mut len)?;
+
+        // SAFETY: `f` is required to have initialized `storage` up to at
+        // least `len` bytes before returning successfully.
+        unsafe { Self::read(storage.as_ptr(), len as usize) }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -134,6 +228,20 @@ impl fmt::Debug for SocketAddrAny {
             Self::Xdp(xdp) => xdp.fmt(fmt),
             #[cfg(target_os = "linux")]
             Self::Netlink(nl) => nl.fmt(fmt),
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "dragonfly"
+            ))]
+            Self::Link(link) => link.fmt(fmt),
+            #[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+            Self::Vsock(vsock) => vsock.fmt(fmt),
+            #[cfg(target_os = "linux")]
+            Self::Alg(alg) => alg.fmt(fmt),
         }
     }
 }
@@ -162,6 +270,68 @@ unsafe impl SocketAddress for SocketAddrAny {
             Self::Xdp(a) => a.with_sockaddr(f),
             #[cfg(target_os = "linux")]
             Self::Netlink(a) => a.with_sockaddr(f),
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "dragonfly"
+            ))]
+            Self::Link(a) => a.with_sockaddr(f),
+            #[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+            Self::Vsock(a) => a.with_sockaddr(f),
+            #[cfg(target_os = "linux")]
+            Self::Alg(a) => a.with_sockaddr(f),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{SocketAddrAny, SocketAddrStorage};
+    use crate::net::{Ipv4Addr, SocketAddrV4, SocketAddress};
+    use core::mem::MaybeUninit;
+
+    #[test]
+    fn try_new_decodes_os_filled_storage() {
+        let addr = SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 8080);
+
+        let mut storage = MaybeUninit::<SocketAddrStorage>::zeroed();
+        let len = addr.with_sockaddr(|ptr, len| unsafe {
+            core::ptr::copy_nonoverlapping(
+                ptr.cast::<u8>(),
+                storage.as_mut_ptr().cast::<u8>(),
+                len as usize,
+            );
+            len as usize
+        });
+
+        let decoded =
+            SocketAddrAny::try_new(unsafe { &*storage.as_ptr() }, len).unwrap();
+        assert_eq!(decoded, SocketAddrAny::V4(addr));
+    }
+
+    #[test]
+    fn initialize_decodes_whatever_f_writes() {
+        let addr = SocketAddrV4::new(Ipv4Addr::new(192, 168, 1, 1), 443);
+
+        let decoded = unsafe {
+            SocketAddrAny::initialize(|storage, len| {
+                *len = addr.with_sockaddr(|ptr, sockaddr_len| {
+                    core::ptr::copy_nonoverlapping(
+                        ptr.cast::<u8>(),
+                        storage.as_mut_ptr().cast::<u8>(),
+                        sockaddr_len as usize,
+                    );
+                    sockaddr_len
+                });
+                Ok(())
+            })
+        }
+        .unwrap();
+
+        assert_eq!(decoded, SocketAddrAny::V4(addr));
+    }
+}
@@ -13,8 +13,26 @@ use super::SocketAddrUnix;
 
 /// A trait abstracting over the types that can be passed as a `sockaddr`.
 ///
-/// Safety: by implementing this trait, you assert that the values returned
-/// by the trait methods can be passed to the system calls that accept `sockaddr`.
+/// This is the extension point for custom address families: implement it
+/// for your own `sockaddr_*`-wrapping type (eg. for `AF_CAN`, `AF_RXRPC`, or
+/// `AF_TIPC`) and pass that type directly to [`connect`], [`bind`],
+/// [`sendto`], and [`sendmsg`] without converting through [`SocketAddrAny`].
+///
+/// [`connect`]: crate::net::connect
+/// [`bind`]: crate::net::bind
+/// [`sendto`]: crate::net::sendto
+/// [`sendmsg`]: crate::net::sendmsg
+/// [`SocketAddrAny`]: super::SocketAddrAny
+///
+/// # Safety
+///
+/// By implementing this trait, you assert that:
+///  - [`Self::encode`] returns a value that's valid to pass as a `sockaddr`
+///    to the system calls that accept one.
+///  - If overridden, [`Self::with_sockaddr`] passes a pointer and length
+///    that are valid for reads of `length` bytes for the duration of the
+///    call to `f`, and that describe a complete, correctly-encoded
+///    `sockaddr_*` for this address's family.
 pub unsafe trait SocketAddress {
     /// The corresponding C `sockaddr_*` type.
     type CSockAddr;
@@ -27,7 +45,8 @@ pub unsafe trait SocketAddress {
     /// C type can pass it directly without a copy.
     ///
     /// The default implementation passes a pointer to a stack variable containing the
-    /// result of `encode`, and `size_of::<Self::CSockAddr>()`.
+    /// result of `encode`, and `size_of::<Self::CSockAddr>()`. Override it when `Self`
+    /// already stores its `CSockAddr` so that a stack copy isn't needed.
     fn with_sockaddr<R>(&self, f: impl FnOnce(*const c::sockaddr, c::socklen_t) -> R) -> R {
         let addr = self.encode();
         let ptr = (&addr as *const Self::CSockAddr).cast();
@@ -0,0 +1,91 @@
+//! `AF_VSOCK` socket addresses, for communication between a hypervisor and
+//! its virtual machines.
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::net::SocketAddress;
+#[cfg(feature = "std")]
+use core::fmt;
+
+/// Wildcard CID, matching any context id. Used with `bind` to listen on all
+/// CIDs.
+pub const VMADDR_CID_ANY: u32 = u32::MAX;
+
+/// The CID of the hypervisor.
+pub const VMADDR_CID_HYPERVISOR: u32 = 0;
+
+/// The CID used to refer to the local communication (loopback).
+pub const VMADDR_CID_LOCAL: u32 = 1;
+
+/// The CID of the host, when calling from a guest.
+pub const VMADDR_CID_HOST: u32 = 2;
+
+/// Wildcard port, matching any port. Used with `bind` to listen on all
+/// ports.
+pub const VMADDR_PORT_ANY: u32 = u32::MAX;
+
+/// `struct sockaddr_vm`
+#[doc(alias = "sockaddr_vm")]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct SocketAddrVsock {
+    cid: u32,
+    port: u32,
+}
+
+impl SocketAddrVsock {
+    /// Construct a new `SocketAddrVsock` from a context id and a port.
+    #[inline]
+    pub const fn new(cid: u32, port: u32) -> Self {
+        Self { cid, port }
+    }
+
+    /// Returns the 32-bit context id.
+    #[inline]
+    pub const fn cid(&self) -> u32 {
+        self.cid
+    }
+
+    /// Returns the 32-bit port.
+    #[inline]
+    pub const fn port(&self) -> u32 {
+        self.port
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for SocketAddrVsock {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SocketAddrVsock")
+            .field("cid", &self.cid)
+            .field("port", &self.port)
+            .finish()
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "ios"))]
+unsafe impl SocketAddress for SocketAddrVsock {
+    type CSockAddr = c::sockaddr_vm;
+
+    fn encode(&self) -> Self::CSockAddr {
+        crate::backend::net::write_sockaddr::encode_sockaddr_vsock(self)
+    }
+}
+
+#[cfg(all(
+    test,
+    any(target_os = "linux", target_os = "macos", target_os = "ios")
+))]
+mod tests {
+    use super::SocketAddrVsock;
+    use crate::net::{SocketAddrAny, SocketAddress};
+
+    #[test]
+    fn round_trips_through_encode_and_read() {
+        let addr = SocketAddrVsock::new(3, 1234);
+
+        let decoded = addr
+            .with_sockaddr(|ptr, len| unsafe { SocketAddrAny::read(ptr.cast(), len as usize) });
+
+        assert_eq!(decoded.unwrap(), SocketAddrAny::Vsock(addr));
+    }
+}